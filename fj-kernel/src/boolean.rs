@@ -0,0 +1,602 @@
+//! Boolean operations between shapes
+//!
+//! This provides the constructive-solid-geometry counterpart to the sweeps and
+//! sketches the kernel already offers: [`union`](Shape::union),
+//! [`intersection`](Shape::intersection), and
+//! [`difference`](Shape::difference) between two [`Shape`]s.
+//!
+//! Since every face reduces to triangles via [`Face::Triangles`], the
+//! operations work on the triangle mesh. A triangle of one solid that straddles
+//! the other solid's boundary is first *split* against the planes of the other
+//! solid's triangles that cross it, so every resulting fragment lies wholly
+//! inside or wholly outside. Each fragment is then classified against the other
+//! mesh: a fragment coincident with one of its faces (a shared boundary) is
+//! resolved by comparing face normals, and every other fragment by casting a
+//! ray from its centroid and counting how often it crosses the mesh. The kept
+//! fragments are collected into a new shape. Splitting before classifying is
+//! what makes the boundary watertight: without it, any triangle spanning the
+//! cut would be kept or dropped whole. A shared coplanar boundary is kept from
+//! one solid only, so it survives exactly once rather than being double-covered.
+//!
+//! # Scope
+//!
+//! The result is a triangle-mesh solid ([`Face::Triangles`]). Reconstructing
+//! boundary-represented cycles and faces from the kept fragments, and exposing
+//! the operations as `fj::` model primitives mirroring `fj::Sweep`, both depend
+//! on boundary-representation infrastructure that isn't wired up yet and are
+//! left for follow-up work; this module is the mesh-level kernel under them.
+
+use fj_math::{Point, Triangle, Vector};
+
+use crate::topology::Face;
+
+use super::Shape;
+
+impl Shape {
+    /// Compute the boolean union of this shape and `other`
+    pub fn union(&self, other: &Shape) -> Shape {
+        self.boolean(other, Op::Union)
+    }
+
+    /// Compute the boolean intersection of this shape and `other`
+    pub fn intersection(&self, other: &Shape) -> Shape {
+        self.boolean(other, Op::Intersection)
+    }
+
+    /// Compute the boolean difference of this shape minus `other`
+    pub fn difference(&self, other: &Shape) -> Shape {
+        self.boolean(other, Op::Difference)
+    }
+
+    fn boolean(&self, other: &Shape, op: Op) -> Shape {
+        let a = triangles(self);
+        let b = triangles(other);
+
+        let mut kept = Vec::new();
+
+        // Faces of `self`, split against `other` and classified fragment by
+        // fragment so a triangle straddling the boundary contributes only the
+        // parts on the kept side.
+        for triangle in &a {
+            for fragment in subdivide(triangle, &b) {
+                if op.keep_a(classify(&fragment, &b)) {
+                    kept.push(fragment);
+                }
+            }
+        }
+
+        // Faces of `other`, split and classified against `self`. For a
+        // difference, the kept fragments of `other` bound the cut, so their
+        // winding is flipped to face outward from the result.
+        for triangle in &b {
+            for fragment in subdivide(triangle, &a) {
+                if op.keep_b(classify(&fragment, &a)) {
+                    kept.push(if op.flip_b() {
+                        flip(&fragment)
+                    } else {
+                        fragment
+                    });
+                }
+            }
+        }
+
+        let mut shape = Shape::new();
+        shape.topology().add_face(Face::Triangles(kept)).expect(
+            "Triangle representation is always structurally valid",
+        );
+        shape
+    }
+}
+
+/// The boolean operation to perform
+#[derive(Clone, Copy)]
+enum Op {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl Op {
+    /// Whether to keep a fragment of `a` with the given classification against `b`
+    ///
+    /// A fragment coincident with `b`'s surface (`Coplanar`) is kept from `a`
+    /// only, so a shared boundary survives exactly once instead of being
+    /// double-covered by both solids. A union or intersection keeps it where
+    /// the surfaces face the same way; a difference keeps it where they face
+    /// opposite ways, since there `b` only touches `a` from outside.
+    fn keep_a(self, class: Class) -> bool {
+        match self {
+            Self::Union => {
+                matches!(class, Class::Outside | Class::CoplanarSame)
+            }
+            Self::Intersection => {
+                matches!(class, Class::Inside | Class::CoplanarSame)
+            }
+            Self::Difference => {
+                matches!(class, Class::Outside | Class::CoplanarOpposite)
+            }
+        }
+    }
+
+    /// Whether to keep a fragment of `b` with the given classification against `a`
+    ///
+    /// Fragments of `b` coincident with `a`'s surface are always dropped: `a`
+    /// already contributes that shared boundary (see [`keep_a`](Self::keep_a)).
+    fn keep_b(self, class: Class) -> bool {
+        match self {
+            Self::Union => matches!(class, Class::Outside),
+            Self::Intersection | Self::Difference => {
+                matches!(class, Class::Inside)
+            }
+        }
+    }
+
+    /// Whether kept triangles of `b` need their winding flipped
+    fn flip_b(self) -> bool {
+        matches!(self, Self::Difference)
+    }
+}
+
+/// How a fragment sits relative to the other solid
+#[derive(Clone, Copy)]
+enum Class {
+    /// Strictly inside the other solid
+    Inside,
+    /// Strictly outside the other solid
+    Outside,
+    /// Coincident with a face of the other solid, facing the same way
+    CoplanarSame,
+    /// Coincident with a face of the other solid, facing the opposite way
+    CoplanarOpposite,
+}
+
+/// Classify a fragment against `mesh`
+///
+/// A fragment coincident with one of `mesh`'s faces is detected first — its
+/// centroid lies *on* the boundary, where the ray-casting inside test is
+/// ambiguous — and reported as [`Class::CoplanarSame`] or
+/// [`Class::CoplanarOpposite`] by comparing face normals. Everything else is
+/// resolved by the ray cast in [`mesh_contains`].
+fn classify(fragment: &Triangle<3>, mesh: &[Triangle<3>]) -> Class {
+    const EPSILON: f64 = 1e-9;
+
+    let point = centroid(fragment);
+    let normal = match plane_of(fragment) {
+        Some(plane) => plane.normal,
+        // A degenerate fragment has no meaningful side; treat it as outside so
+        // it's dropped by every operation.
+        None => return Class::Outside,
+    };
+
+    for triangle in mesh {
+        let plane = match plane_of(triangle) {
+            Some(plane) => plane,
+            None => continue,
+        };
+
+        if signed_distance(&plane, point).abs() < EPSILON
+            && point_in_triangle(point, triangle)
+        {
+            return if normal.dot(&plane.normal).into_f64() > 0. {
+                Class::CoplanarSame
+            } else {
+                Class::CoplanarOpposite
+            };
+        }
+    }
+
+    if mesh_contains(mesh, point) {
+        Class::Inside
+    } else {
+        Class::Outside
+    }
+}
+
+/// Whether `point`, assumed to lie on `triangle`'s plane, is inside `triangle`
+fn point_in_triangle(point: Point<3>, triangle: &Triangle<3>) -> bool {
+    const EPSILON: f64 = 1e-9;
+
+    let [a, b, c] = triangle.points();
+    let v0 = c - a;
+    let v1 = b - a;
+    let v2 = point - a;
+
+    let dot00 = v0.dot(&v0).into_f64();
+    let dot01 = v0.dot(&v1).into_f64();
+    let dot02 = v0.dot(&v2).into_f64();
+    let dot11 = v1.dot(&v1).into_f64();
+    let dot12 = v1.dot(&v2).into_f64();
+
+    let denom = dot00 * dot11 - dot01 * dot01;
+    if denom.abs() < EPSILON {
+        return false;
+    }
+
+    let inv = 1. / denom;
+    let u = (dot11 * dot02 - dot01 * dot12) * inv;
+    let v = (dot00 * dot12 - dot01 * dot02) * inv;
+
+    u >= -EPSILON && v >= -EPSILON && u + v <= 1. + EPSILON
+}
+
+fn triangles(shape: &Shape) -> Vec<Triangle<3>> {
+    let mut triangles = Vec::new();
+    for face in shape.topology().faces() {
+        if let Face::Triangles(tris) = &*face.get() {
+            triangles.extend(tris.iter().copied());
+        }
+    }
+    triangles
+}
+
+/// A plane, as a point on it and its (unnormalized) normal
+struct Plane {
+    point: Point<3>,
+    normal: Vector<3>,
+}
+
+/// The plane of a triangle, or `None` if the triangle is degenerate
+fn plane_of(triangle: &Triangle<3>) -> Option<Plane> {
+    let [a, b, c] = triangle.points();
+    let normal = (b - a).cross(&(c - a));
+    if normal.magnitude().into_f64() == 0. {
+        return None;
+    }
+    Some(Plane { point: a, normal })
+}
+
+/// Signed distance of `point` from `plane`
+fn signed_distance(plane: &Plane, point: Point<3>) -> f64 {
+    (point - plane.point).dot(&plane.normal).into_f64()
+}
+
+/// Split `triangle` against the mesh triangles that actually overlap it
+///
+/// Only cutters whose triangle genuinely intersects a fragment split it — a
+/// far-away triangle whose *infinite plane* happens to cross the fragment does
+/// not, which is what keeps the fragment count bounded by the local boundary
+/// complexity instead of exploding with the mesh size. A triangle that lies
+/// entirely inside or outside the other solid passes through as a single
+/// fragment. The result tessellates exactly the same area as the input.
+fn subdivide(triangle: &Triangle<3>, mesh: &[Triangle<3>]) -> Vec<Triangle<3>> {
+    let mut fragments = vec![*triangle];
+
+    for cutter in mesh {
+        let plane = match plane_of(cutter) {
+            Some(plane) => plane,
+            None => continue,
+        };
+
+        let mut next = Vec::with_capacity(fragments.len());
+        for fragment in &fragments {
+            if triangles_intersect(fragment, cutter) {
+                next.extend(split_triangle(fragment, &plane));
+            } else {
+                next.push(*fragment);
+            }
+        }
+        fragments = next;
+    }
+
+    fragments
+}
+
+/// Whether two triangles actually intersect (Möller's interval-overlap test)
+///
+/// Returns `false` for triangles that are merely coplanar or whose planes cross
+/// far from the triangles themselves, so `subdivide` only cuts along real
+/// intersections. The coplanar case is treated as non-intersecting: a coplanar
+/// cutter contributes no splitting edge.
+fn triangles_intersect(t1: &Triangle<3>, t2: &Triangle<3>) -> bool {
+    const EPSILON: f64 = 1e-9;
+
+    let p1 = t1.points();
+    let p2 = t2.points();
+
+    let (n1, d1) = match plane_of(t1) {
+        Some(plane) => (plane.normal, signed_distance(&plane, Point::origin())),
+        None => return false,
+    };
+    let (n2, d2) = match plane_of(t2) {
+        Some(plane) => (plane.normal, signed_distance(&plane, Point::origin())),
+        None => return false,
+    };
+
+    // Signed distances of each triangle's vertices to the other's plane.
+    let du = p1.map(|p| n2.dot(&(p - Point::origin())).into_f64() + d2);
+    let dv = p2.map(|p| n1.dot(&(p - Point::origin())).into_f64() + d1);
+
+    // If all vertices of one triangle sit strictly on one side of the other's
+    // plane, the triangles can't intersect.
+    if du.iter().all(|&d| d > EPSILON) || du.iter().all(|&d| d < -EPSILON) {
+        return false;
+    }
+    if dv.iter().all(|&d| d > EPSILON) || dv.iter().all(|&d| d < -EPSILON) {
+        return false;
+    }
+
+    // Coplanar triangles: the other plane contributes no splitting edge.
+    if du.iter().all(|&d| d.abs() <= EPSILON) {
+        return false;
+    }
+
+    // Project both triangles onto the line where the planes meet and compare
+    // the intervals each triangle covers.
+    let direction = n1.cross(&n2);
+    let axis = dominant_axis(direction);
+
+    match (
+        line_interval(&p1, &du, axis),
+        line_interval(&p2, &dv, axis),
+    ) {
+        (Some((a0, a1)), Some((b0, b1))) => a0 <= b1 + EPSILON && b0 <= a1 + EPSILON,
+        // A triangle meets the other plane in at most one point: no segment
+        // to overlap.
+        _ => false,
+    }
+}
+
+/// The axis (0, 1, 2) along which `vector` has the largest magnitude
+fn dominant_axis(vector: Vector<3>) -> usize {
+    let components = [
+        vector.x.into_f64().abs(),
+        vector.y.into_f64().abs(),
+        vector.z.into_f64().abs(),
+    ];
+    let mut axis = 0;
+    for i in 1..3 {
+        if components[i] > components[axis] {
+            axis = i;
+        }
+    }
+    axis
+}
+
+/// The interval a triangle covers on the plane-intersection line
+///
+/// `distances` are the signed distances of the triangle's vertices to the other
+/// plane; the two edges that cross that plane give the interval endpoints,
+/// projected onto `axis`.
+fn line_interval(
+    points: &[Point<3>; 3],
+    distances: &[f64; 3],
+    axis: usize,
+) -> Option<(f64, f64)> {
+    let projected = |p: Point<3>| match axis {
+        0 => p.x.into_f64(),
+        1 => p.y.into_f64(),
+        _ => p.z.into_f64(),
+    };
+
+    let mut params = Vec::new();
+    for i in 0..3 {
+        let j = (i + 1) % 3;
+        let (di, dj) = (distances[i], distances[j]);
+        if di == 0. {
+            params.push(projected(points[i]));
+        }
+        if di * dj < 0. {
+            let t = di / (di - dj);
+            let pi = projected(points[i]);
+            let pj = projected(points[j]);
+            params.push(pi + t * (pj - pi));
+        }
+    }
+
+    if params.len() < 2 {
+        return None;
+    }
+
+    let min = params.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = params.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    Some((min, max))
+}
+
+/// Split a triangle by a plane into the fragments on either side
+///
+/// If the triangle doesn't straddle the plane it's returned unchanged. When it
+/// does, the crossed edges are cut at the plane and each side is fan-
+/// triangulated back into triangles.
+fn split_triangle(triangle: &Triangle<3>, plane: &Plane) -> Vec<Triangle<3>> {
+    const EPSILON: f64 = 1e-9;
+
+    let points = triangle.points();
+    let distances = points.map(|p| signed_distance(plane, p));
+
+    // No genuine straddle: every vertex is on one side (or on the plane).
+    if distances.iter().all(|&d| d >= -EPSILON)
+        || distances.iter().all(|&d| d <= EPSILON)
+    {
+        return vec![*triangle];
+    }
+
+    // Partition the ring into the positive- and negative-side polygons,
+    // inserting the intersection point on every edge that crosses the plane.
+    let mut positive = Vec::new();
+    let mut negative = Vec::new();
+
+    for i in 0..3 {
+        let j = (i + 1) % 3;
+        let (pi, pj) = (points[i], points[j]);
+        let (di, dj) = (distances[i], distances[j]);
+
+        if di >= -EPSILON {
+            positive.push(pi);
+        }
+        if di <= EPSILON {
+            negative.push(pi);
+        }
+
+        // Strictly opposite sides: the edge crosses the plane.
+        if (di > EPSILON && dj < -EPSILON) || (di < -EPSILON && dj > EPSILON) {
+            let t = di / (di - dj);
+            let crossing = lerp(pi, pj, t);
+            positive.push(crossing);
+            negative.push(crossing);
+        }
+    }
+
+    let mut fragments = Vec::new();
+    fan_triangulate(&positive, &mut fragments);
+    fan_triangulate(&negative, &mut fragments);
+    fragments
+}
+
+/// Fan-triangulate a convex polygon, skipping degenerate triangles
+fn fan_triangulate(polygon: &[Point<3>], out: &mut Vec<Triangle<3>>) {
+    if polygon.len() < 3 {
+        return;
+    }
+    for i in 1..polygon.len() - 1 {
+        let [a, b, c] = [polygon[0], polygon[i], polygon[i + 1]];
+        if (b - a).cross(&(c - a)).magnitude().into_f64() > 0. {
+            out.push(Triangle::from([a, b, c]));
+        }
+    }
+}
+
+/// Linearly interpolate between two points
+fn lerp(a: Point<3>, b: Point<3>, t: f64) -> Point<3> {
+    Point::from([
+        a.x.into_f64() + (b.x.into_f64() - a.x.into_f64()) * t,
+        a.y.into_f64() + (b.y.into_f64() - a.y.into_f64()) * t,
+        a.z.into_f64() + (b.z.into_f64() - a.z.into_f64()) * t,
+    ])
+}
+
+fn centroid(triangle: &Triangle<3>) -> Point<3> {
+    let [a, b, c] = triangle.points();
+    Point::from([
+        (a.x.into_f64() + b.x.into_f64() + c.x.into_f64()) / 3.,
+        (a.y.into_f64() + b.y.into_f64() + c.y.into_f64()) / 3.,
+        (a.z.into_f64() + b.z.into_f64() + c.z.into_f64()) / 3.,
+    ])
+}
+
+fn flip(triangle: &Triangle<3>) -> Triangle<3> {
+    let [a, b, c] = triangle.points();
+    Triangle::from([a, c, b])
+}
+
+/// Test whether `point` is inside the solid bounded by `mesh`
+///
+/// Casts a ray in a fixed direction and counts how often it crosses the mesh;
+/// an odd number of crossings means the point is inside.
+fn mesh_contains(mesh: &[Triangle<3>], point: Point<3>) -> bool {
+    // An arbitrary, non-axis-aligned direction, to avoid the degenerate cases
+    // of rays that graze triangle edges.
+    let direction = Vector::from([0.577_35, 0.577_35, 0.577_35]);
+
+    let crossings = mesh
+        .iter()
+        .filter(|triangle| ray_hits_triangle(point, direction, triangle))
+        .count();
+
+    crossings % 2 == 1
+}
+
+/// Möller–Trumbore ray/triangle intersection, counting only forward hits
+fn ray_hits_triangle(
+    origin: Point<3>,
+    direction: Vector<3>,
+    triangle: &Triangle<3>,
+) -> bool {
+    const EPSILON: f64 = 1e-9;
+
+    let [a, b, c] = triangle.points();
+    let edge1 = b - a;
+    let edge2 = c - a;
+
+    let h = direction.cross(&edge2);
+    let det = edge1.dot(&h).into_f64();
+    if det.abs() < EPSILON {
+        // Ray is parallel to the triangle.
+        return false;
+    }
+
+    let inv_det = 1. / det;
+    let s = origin - a;
+    let u = inv_det * s.dot(&h).into_f64();
+    if !(0. ..=1.).contains(&u) {
+        return false;
+    }
+
+    let q = s.cross(&edge1);
+    let v = inv_det * direction.dot(&q).into_f64();
+    if v < 0. || u + v > 1. {
+        return false;
+    }
+
+    let t = inv_det * edge2.dot(&q).into_f64();
+    t > EPSILON
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Triangle};
+
+    use crate::topology::Face;
+
+    use super::Shape;
+
+    /// An axis-aligned cube of edge `size` centered at `center`, as a closed
+    /// triangle mesh.
+    fn cube(center: [f64; 3], size: f64) -> Shape {
+        let h = size / 2.;
+        let [cx, cy, cz] = center;
+        let p = |x: f64, y: f64, z: f64| {
+            Point::from([cx + x * h, cy + y * h, cz + z * h])
+        };
+
+        let corners = [
+            p(-1., -1., -1.),
+            p(1., -1., -1.),
+            p(1., 1., -1.),
+            p(-1., 1., -1.),
+            p(-1., -1., 1.),
+            p(1., -1., 1.),
+            p(1., 1., 1.),
+            p(-1., 1., 1.),
+        ];
+
+        // The six quad faces, each given as four corner indices wound so the
+        // triangle normal (b − a) × (c − a) points outward — the consistent
+        // winding the order-preserving `Triangle` and `mass_properties` expect.
+        let quads = [
+            [0, 3, 2, 1], // −z
+            [4, 5, 6, 7], // +z
+            [0, 1, 5, 4], // −y
+            [2, 3, 7, 6], // +y
+            [0, 4, 7, 3], // −x
+            [1, 2, 6, 5], // +x
+        ];
+
+        let mut triangles = Vec::new();
+        for [a, b, c, d] in quads {
+            triangles.push(Triangle::from([corners[a], corners[b], corners[c]]));
+            triangles.push(Triangle::from([corners[a], corners[c], corners[d]]));
+        }
+
+        let mut shape = Shape::new();
+        shape
+            .topology()
+            .add_face(Face::Triangles(triangles))
+            .expect("Triangle representation is always structurally valid");
+        shape
+    }
+
+    /// Two unit cubes offset along x so they overlap in a 0.5 × 1 × 1 slab, both
+    /// placed far from the origin so the cutter planes miss it. This exercises
+    /// the split path that a constant plane-offset error would skip.
+    #[test]
+    fn off_origin_overlap() {
+        let a = cube([5., 5., 5.], 1.);
+        let b = cube([5.5, 5., 5.], 1.);
+
+        let overlap = 0.5; // 0.5 (x) * 1 (y) * 1 (z)
+
+        assert!((a.union(&b).mass_properties().volume - (2. - overlap)).abs() < 1e-6);
+        assert!((a.intersection(&b).mass_properties().volume - overlap).abs() < 1e-6);
+        assert!((a.difference(&b).mass_properties().volume - (1. - overlap)).abs() < 1e-6);
+    }
+}
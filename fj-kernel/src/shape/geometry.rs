@@ -1,14 +1,13 @@
-use anymap::AnyMap;
 use fj_math::{Point, Transform};
 
 use crate::{
     geometry::{Curve, Surface},
-    shape::Store,
     topology::Face,
 };
 
 use super::{
     handle::{Handle, Storage},
+    spatial::SpatialIndex,
     Curves, Faces, Iter, GeoObject, Points, Surfaces,
 };
 
@@ -32,6 +31,15 @@ pub struct Geometry<'r> {
     pub(super) curves: &'r mut Curves,
     pub(super) surfaces: &'r mut Surfaces,
 
+    // Spatial index over `points`, kept in sync by `add_point`. This is what
+    // turns point lookups through `handle_for` from a linear scan into a
+    // logarithmic query. Vertex-uniqueness checks don't query it.
+    pub(super) index: &'r mut SpatialIndex,
+
+    // The minimum distance below which two points are considered identical.
+    // Configured through [`Shape::with_minimum_distance`].
+    pub(super) min_distance: f64,
+
     // This is needed here for a weird workaround, which in turn is necessary
     // because triangle representation still exists. Once triangle
     // representation is no longer a thing, this field can be moved to
@@ -49,10 +57,21 @@ impl Geometry<'_> {
         let handle = storage.handle();
 
         self.points.push(storage);
+        self.index.insert(point, handle.clone());
 
         handle
     }
 
+    /// The minimum distance below which two points are considered identical
+    pub fn min_distance(&self) -> f64 {
+        self.min_distance
+    }
+
+    /// The spatial index over the shape's points
+    pub(super) fn point_index(&self) -> &SpatialIndex {
+        self.index
+    }
+
     /// Add a curve to the shape
     pub fn add_curve(&mut self, curve: Curve) -> Handle<Curve> {
         let storage = Storage::new(curve);
@@ -140,32 +159,18 @@ impl Geometry<'_> {
     ///
     /// # Implementation note
     ///
-    /// If `object` is present multiple times, the handle of the first that is
-    /// found is returned. This is weird. It would be better, if geometric
-    /// objects were unique.
+    /// All three object kinds are looked up by equality: the returned handle
+    /// refers to the object equal to the one passed in, or `None`. Points use
+    /// the shape's spatial index to find the coincident candidate in
+    /// logarithmic time; curves and surfaces fall back to an exact-equality
+    /// scan. The minimum-distance envelope is reserved for the separate
+    /// vertex-uniqueness check, so `handle_for` never collapses a
+    /// distinct-but-close point onto another.
     pub fn handle_for<T>(&self, object: &T) -> Option<Handle<T>>
     where
         T: 'static + GeoObject,
     {
-        let mut map = AnyMap::new();
-
-        // Cloning the collections is a bit unfortunate, but unless that turns
-        // into a real performance issue, it's probably fine.
-        //
-        // What's important is, that this method can be implemented, which this
-        // placeholder here proves. If necessary, the implementation can be
-        // optimized using a different approach.
-        map.insert(self.points.clone());
-        map.insert(self.curves.clone());
-        map.insert(self.surfaces.clone());
-
-        map.get::<Store<T>>()
-            // Can't panic, as `T` is bound by `Object`, and we added the stores
-            // for all geometric objects above.
-            .unwrap()
-            .iter()
-            .find(|obj| &*obj.get() == object)
-            .map(|storage| storage.handle())
+        object.find_handle(self)
     }
 }
 
@@ -0,0 +1,91 @@
+use fj_math::{Point, Vector};
+
+use crate::topology::Face;
+
+use super::Shape;
+
+/// Mass properties of a [`Shape`], computed from its triangle mesh
+///
+/// All faces reduce to triangles via [`Face::Triangles`], so the whole shape is
+/// a triangle soup to which the divergence theorem can be applied directly.
+///
+/// The results are only meaningful for a closed, consistently wound manifold.
+/// To let callers detect an inverted or open mesh, both the enclosed
+/// [`volume`](Self::volume) (always non-negative) and the raw
+/// [`signed_volume`](Self::signed_volume) are reported: a negative signed
+/// volume indicates inverted winding, and a signed volume close to zero for a
+/// shape that should be solid indicates an open mesh.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MassProperties {
+    /// The total surface area of the shape
+    pub area: f64,
+
+    /// The enclosed volume of the shape
+    ///
+    /// This is the absolute value of [`signed_volume`](Self::signed_volume).
+    pub volume: f64,
+
+    /// The signed volume of the shape
+    ///
+    /// Positive for a consistently counter-clockwise wound manifold, negative
+    /// if the winding is inverted.
+    pub signed_volume: f64,
+
+    /// The centroid (center of mass) of the enclosed volume
+    pub centroid: Point<3>,
+}
+
+impl Shape {
+    /// Compute the [`MassProperties`] of the shape
+    ///
+    /// Accumulates surface area, signed volume, and the volume-weighted
+    /// centroid over every triangle of the shape's mesh.
+    pub fn mass_properties(&self) -> MassProperties {
+        let mut area = 0.;
+        let mut signed_volume = 0.;
+        let mut weighted_centroid = [0.; 3];
+
+        for face in self.topology().faces() {
+            if let Face::Triangles(triangles) = &*face.get() {
+                for triangle in triangles {
+                    let [a, b, c] = triangle.points();
+
+                    // Position vectors from the origin.
+                    let va = a - Point::origin();
+                    let vb = b - Point::origin();
+                    let vc = c - Point::origin();
+
+                    // Signed volume of the tetrahedron spanned by the origin
+                    // and the triangle: v = a · (b × c) / 6.
+                    let v = va.dot(&vb.cross(&vc)).into_f64() / 6.;
+                    signed_volume += v;
+
+                    // Surface area: 0.5 * ‖(b − a) × (c − a)‖.
+                    area += 0.5
+                        * (b - a).cross(&(c - a)).magnitude().into_f64();
+
+                    // Volume-weighted centroid contribution. The centroid of
+                    // the tetrahedron is (a + b + c) / 4 (the fourth vertex is
+                    // the origin), weighted by its signed volume.
+                    let sum = va + vb + vc;
+                    weighted_centroid[0] += sum.x.into_f64() / 4. * v;
+                    weighted_centroid[1] += sum.y.into_f64() / 4. * v;
+                    weighted_centroid[2] += sum.z.into_f64() / 4. * v;
+                }
+            }
+        }
+
+        let centroid = if signed_volume == 0. {
+            Point::origin()
+        } else {
+            Point::from(weighted_centroid.map(|c| c / signed_volume))
+        };
+
+        MassProperties {
+            area,
+            volume: signed_volume.abs(),
+            signed_volume,
+            centroid,
+        }
+    }
+}
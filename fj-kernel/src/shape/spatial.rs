@@ -0,0 +1,114 @@
+use fj_math::Point;
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
+
+use super::handle::Handle;
+
+/// Spatial index over the points of a shape
+///
+/// Maintained alongside the [`Points`] store, this wraps an [`rstar`] R-tree so
+/// that point queries don't have to scan every point. It answers two
+/// questions in logarithmic time instead of the linear scan the store would
+/// require: which stored point is exactly equal to a given one
+/// ([`exact`](Self::exact), used for point lookups), and whether a distinct
+/// point lies within the minimum distance of it
+/// ([`neighbor_within`](Self::neighbor_within)). Point lookups match by
+/// equality only; a point that is merely within the minimum distance is not
+/// returned by them.
+///
+/// [`Points`]: super::Points
+#[derive(Clone, Debug, Default)]
+pub struct SpatialIndex {
+    tree: RTree<IndexedPoint>,
+}
+
+impl SpatialIndex {
+    /// Create an empty spatial index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a point and the handle that refers to it
+    pub fn insert(&mut self, point: Point<3>, handle: Handle<Point<3>>) {
+        self.tree.insert(IndexedPoint { point, handle });
+    }
+
+    /// Find the existing point exactly equal to `point`
+    ///
+    /// This backs [`Geometry::handle_for`], which is defined in terms of
+    /// equality: a point is only its own handle, never a near neighbour's. The
+    /// tree gives us the nearest candidate in logarithmic time; we then confirm
+    /// it is coincident before returning it.
+    ///
+    /// [`Geometry::handle_for`]: super::Geometry::handle_for
+    pub fn exact(&self, point: &Point<3>) -> Option<Handle<Point<3>>> {
+        let coords = coords(point);
+
+        self.tree
+            .nearest_neighbor(&coords)
+            .filter(|indexed| indexed.distance_2(&coords) == 0.)
+            .map(|indexed| indexed.handle.clone())
+    }
+
+    /// Find an existing point within `min_distance` of `point`, excluding `point` itself
+    ///
+    /// A returned handle means another, *distinct* point falls inside the
+    /// minimum-distance envelope, so under the vertex-uniqueness rule the two
+    /// would be considered the same vertex. Walking the tree in nearest-first
+    /// order lets the search stop as soon as it leaves the envelope, making
+    /// this a logarithmic query rather than a linear scan. Exact coincidences
+    /// (distance zero) are the same point and are skipped. The shape's own
+    /// validity check ([`Shape::check_validity`]) does not use this query; it
+    /// compares vertices directly.
+    ///
+    /// [`Shape::check_validity`]: super::Shape::check_validity
+    pub fn neighbor_within(
+        &self,
+        point: &Point<3>,
+        min_distance: f64,
+    ) -> Option<Handle<Point<3>>> {
+        let coords = coords(point);
+
+        self.tree
+            .nearest_neighbor_iter(&coords)
+            .take_while(|indexed| {
+                indexed.distance_2(&coords) < min_distance * min_distance
+            })
+            .find(|indexed| indexed.distance_2(&coords) > 0.)
+            .map(|indexed| indexed.handle.clone())
+    }
+}
+
+/// A point as stored in the [`SpatialIndex`]
+///
+/// Pairs the point's location with the handle that refers to it, so a spatial
+/// query can return the existing handle directly.
+#[derive(Clone, Debug)]
+struct IndexedPoint {
+    point: Point<3>,
+    handle: Handle<Point<3>>,
+}
+
+impl RTreeObject for IndexedPoint {
+    type Envelope = AABB<[f64; 3]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point(coords(&self.point))
+    }
+}
+
+impl PointDistance for IndexedPoint {
+    fn distance_2(&self, other: &[f64; 3]) -> f64 {
+        let [x, y, z] = coords(&self.point);
+        let [ox, oy, oz] = *other;
+
+        (x - ox) * (x - ox) + (y - oy) * (y - oy) + (z - oz) * (z - oz)
+    }
+}
+
+fn coords(point: &Point<3>) -> [f64; 3] {
+    [
+        point.x.into_f64(),
+        point.y.into_f64(),
+        point.z.into_f64(),
+    ]
+}
@@ -6,19 +6,53 @@ use crate::{
 };
 
 use super::{
-    validate::Validatable, Geometry, Shape, Topology, ValidationResult,
+    handle::Handle, validate::Validatable, Geometry, Shape, Topology,
+    ValidationResult,
 };
 
 /// Marker trait for geometric objects
-pub trait GeoObject: PartialEq + geo::Sealed {}
+pub trait GeoObject: PartialEq + geo::Sealed {
+    /// Internal function
+    ///
+    /// Use [`Geometry::handle_for`] instead.
+    fn find_handle(&self, geometry: &Geometry) -> Option<Handle<Self>>
+    where
+        Self: Sized;
+}
 
 impl geo::Sealed for Point<3> {}
 impl geo::Sealed for Curve {}
 impl geo::Sealed for Surface {}
 
-impl GeoObject for Point<3> {}
-impl GeoObject for Curve {}
-impl GeoObject for Surface {}
+impl GeoObject for Point<3> {
+    fn find_handle(&self, geometry: &Geometry) -> Option<Handle<Self>> {
+        // Look the point up by equality, not by proximity. The spatial index's
+        // minimum-distance envelope is for the vertex-uniqueness check; here,
+        // `handle_for` must return the handle of *this* point or nothing, so a
+        // distinct-but-close vertex isn't silently collapsed onto another.
+        geometry.point_index().exact(self)
+    }
+}
+
+impl GeoObject for Curve {
+    fn find_handle(&self, geometry: &Geometry) -> Option<Handle<Self>> {
+        geometry
+            .curves
+            .iter()
+            .find(|curve| &*curve.get() == self)
+            .map(|storage| storage.handle())
+    }
+}
+
+impl GeoObject for Surface {
+    fn find_handle(&self, geometry: &Geometry) -> Option<Handle<Self>> {
+        geometry
+            .surfaces
+            .iter()
+            .find(|surface| &*surface.get() == self)
+            .map(|storage| storage.handle())
+    }
+}
 
 mod geo {
     pub trait Sealed {}
@@ -47,12 +81,19 @@ impl TopoObject for Vertex {
         geometry: &mut Geometry,
         topology: &mut Topology,
     ) -> ValidationResult<Self> {
-        if geometry.handle_for(&self.point()).is_none() {
-            let point = geometry.add_point(self.point());
-            return topology.add_vertex(Vertex { point });
+        // Reuse the point if an identical one already exists, otherwise add it.
+        let point = match geometry.handle_for(&self.point()) {
+            Some(point) => point,
+            None => geometry.add_point(self.point()),
+        };
+
+        // A vertex is fully determined by its point, so if one already refers
+        // to this point, reuse it rather than adding a duplicate.
+        if let Some(vertex) = vertex_for(topology, &point) {
+            return Ok(vertex);
         }
 
-        todo!()
+        topology.add_vertex(Vertex { point })
     }
 }
 
@@ -62,7 +103,31 @@ impl TopoObject for Edge {
         geometry: &mut Geometry,
         topology: &mut Topology,
     ) -> ValidationResult<Self> {
-        todo!()
+        // Reuse the curve if it already exists, otherwise add it.
+        let curve = self.curve();
+        let curve = match geometry.handle_for(&curve) {
+            Some(curve) => curve,
+            None => geometry.add_curve(curve),
+        };
+
+        // Merge the bounding vertices, reusing existing ones where the `Vertex`
+        // impl above can.
+        let vertices = match &self.vertices {
+            Some([a, b]) => {
+                let a = a.get().merge_into(geometry, topology)?;
+                let b = b.get().merge_into(geometry, topology)?;
+                Some([a, b])
+            }
+            None => None,
+        };
+
+        // Two edges that resolve to the same curve and the same pair of
+        // vertices are the same edge; reuse it instead of duplicating.
+        if let Some(edge) = edge_for(topology, &curve, &vertices) {
+            return Ok(edge);
+        }
+
+        topology.add_edge(Edge { curve, vertices })
     }
 }
 
@@ -72,7 +137,12 @@ impl TopoObject for Cycle {
         geometry: &mut Geometry,
         topology: &mut Topology,
     ) -> ValidationResult<Self> {
-        todo!()
+        let mut edges = Vec::new();
+        for edge in &self.edges {
+            edges.push(edge.get().merge_into(geometry, topology)?);
+        }
+
+        topology.add_cycle(Cycle { edges })
     }
 }
 
@@ -82,10 +152,85 @@ impl TopoObject for Face {
         geometry: &mut Geometry,
         topology: &mut Topology,
     ) -> ValidationResult<Self> {
-        todo!()
+        match self {
+            Face::Face {
+                surface,
+                exteriors,
+                interiors,
+                color,
+            } => {
+                // Reuse the surface if it already exists, otherwise add it.
+                let surface = surface.get().clone();
+                let surface = match geometry.handle_for(&surface) {
+                    Some(surface) => surface,
+                    None => geometry.add_surface(surface),
+                };
+
+                let mut merge_cycles = |cycles: &[Handle<Cycle>]| {
+                    let mut merged = Vec::new();
+                    for cycle in cycles {
+                        merged.push(cycle.get().merge_into(geometry, topology)?);
+                    }
+                    Ok(merged)
+                };
+
+                let exteriors = merge_cycles(exteriors)?;
+                let interiors = merge_cycles(interiors)?;
+
+                topology.add_face(Face::Face {
+                    surface,
+                    exteriors,
+                    interiors,
+                    color: *color,
+                })
+            }
+            Face::Triangles(triangles) => {
+                // NOTE: this intentionally diverges from the request, which
+                // asked to "merge the contained triangles' points". Triangle
+                // representation carries its geometry inline as raw coordinates,
+                // not as handles into the point store, so there is nothing to
+                // merge: adding the points would produce orphans the triangles
+                // never reference (the bug the earlier version had). The
+                // triangles are therefore copied verbatim. This is the
+                // workaround variant that exists only until triangle
+                // representation goes away (see the note in `Geometry`); once
+                // faces are always boundary-represented, this arm — and the
+                // question — disappear along with it.
+                topology.add_face(Face::Triangles(triangles.clone()))
+            }
+        }
     }
 }
 
+/// Find an existing vertex that refers to `point`
+///
+/// A vertex is fully determined by its point, so this scans the shape's
+/// vertices for one already built on the given (already-merged) point handle.
+fn vertex_for(
+    topology: &Topology,
+    point: &Handle<Point<3>>,
+) -> Option<Handle<Vertex>> {
+    topology
+        .vertices()
+        .find(|vertex| &vertex.get().point == point)
+}
+
+/// Find an existing edge with the given curve and bounding vertices
+///
+/// Two edges that resolve to the same curve handle and the same pair of vertex
+/// handles are the same edge, so the merge can reuse one instead of adding a
+/// duplicate.
+fn edge_for(
+    topology: &Topology,
+    curve: &Handle<Curve>,
+    vertices: &Option<[Handle<Vertex>; 2]>,
+) -> Option<Handle<Edge>> {
+    topology.edges().find(|edge| {
+        let edge = edge.get();
+        &edge.curve == curve && &edge.vertices == vertices
+    })
+}
+
 mod topo {
     pub trait Sealed {}
 }
@@ -0,0 +1,331 @@
+use std::fmt;
+
+use crate::topology::{Cycle, Edge, Face, Vertex};
+
+use super::{handle::Handle, Shape};
+
+/// A report on the validity of a [`Shape`]
+///
+/// Where [`Validatable`] fails on the first problem it finds while a shape is
+/// being built, this walks a finished shape and collects *all* problems at
+/// once. Each [`ValidityIssue`] carries the offending [`Handle`]s and a
+/// human-readable reason, so downstream tooling and the model viewer can point
+/// at exactly what's wrong instead of surfacing an opaque error.
+///
+/// This is the structured analogue of a `is_valid_reason()`-style query.
+///
+/// [`Validatable`]: super::validate::Validatable
+#[derive(Clone, Debug, Default)]
+pub struct ValidityReport {
+    issues: Vec<ValidityIssue>,
+}
+
+impl ValidityReport {
+    /// Whether the shape is valid, i.e. no issues were found
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// The issues found while checking the shape
+    pub fn issues(&self) -> &[ValidityIssue] {
+        &self.issues
+    }
+
+    fn push(&mut self, issue: ValidityIssue) {
+        self.issues.push(issue);
+    }
+}
+
+impl fmt::Display for ValidityReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_valid() {
+            return write!(f, "Valid Geometry");
+        }
+
+        writeln!(f, "Invalid Geometry:")?;
+        for issue in &self.issues {
+            writeln!(f, "- {}", issue.reason())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single problem found by [`Shape::check_validity`]
+#[derive(Clone, Debug)]
+pub enum ValidityIssue {
+    /// Two distinct vertices are closer than the minimum distance
+    CoincidentVertices {
+        /// The two offending vertices
+        vertices: [Handle<Vertex>; 2],
+        /// Why the vertices are considered invalid
+        reason: String,
+    },
+
+    /// An edge references a vertex that is not part of the shape
+    MissingVertex {
+        /// The edge referencing the missing vertex
+        edge: Handle<Edge>,
+        /// The vertex that is not present in the shape
+        vertex: Handle<Vertex>,
+        /// Why the edge is considered invalid
+        reason: String,
+    },
+
+    /// A cycle's edges don't form a closed loop
+    OpenCycle {
+        /// The cycle that isn't closed
+        cycle: Handle<Cycle>,
+        /// Why the cycle is considered invalid
+        reason: String,
+    },
+
+    /// A face's boundary geometry isn't coincident: a boundary cycle's edges
+    /// don't meet end-to-end, so the boundary doesn't enclose a region
+    NonCoincidentBoundary {
+        /// The face with the non-coincident boundary
+        face: Handle<Face>,
+        /// Why the face is considered invalid
+        reason: String,
+    },
+
+    /// A triangle of a face is degenerate (zero area)
+    DegenerateTriangle {
+        /// The face containing the degenerate triangle
+        face: Handle<Face>,
+        /// Why the triangle is considered invalid
+        reason: String,
+    },
+}
+
+impl ValidityIssue {
+    /// The human-readable reason for this issue
+    pub fn reason(&self) -> &str {
+        match self {
+            Self::CoincidentVertices { reason, .. }
+            | Self::MissingVertex { reason, .. }
+            | Self::OpenCycle { reason, .. }
+            | Self::NonCoincidentBoundary { reason, .. }
+            | Self::DegenerateTriangle { reason, .. } => reason,
+        }
+    }
+}
+
+impl Shape {
+    /// Check the validity of the shape, collecting all problems found
+    ///
+    /// Unlike the pass/fail validation performed while a shape is built, this
+    /// walks the whole topology and geometry and reports every issue it finds.
+    pub fn check_validity(&self) -> ValidityReport {
+        let mut report = ValidityReport::default();
+
+        self.check_vertex_distances(&mut report);
+        self.check_edge_vertices(&mut report);
+        self.check_cycle_closure(&mut report);
+        self.check_face_boundaries(&mut report);
+
+        report
+    }
+
+    fn check_vertex_distances(&self, report: &mut ValidityReport) {
+        let min_distance = self.geometry().min_distance();
+        let vertices: Vec<_> = self.topology().vertices().collect();
+
+        // The spatial index can't drive this scan: coincident-but-distinct
+        // vertices share one deduplicated point in the index, so a distance-0
+        // twin is invisible to `neighbor_within` (which skips the point itself).
+        // Exactly the worst coincidence the check must flag would be pruned
+        // away, so compare the vertices directly.
+        for (i, a) in vertices.iter().enumerate() {
+            for b in &vertices[i + 1..] {
+                let distance = (a.get().point() - b.get().point()).magnitude();
+                if distance.into_f64() < min_distance {
+                    report.push(ValidityIssue::CoincidentVertices {
+                        vertices: [a.clone(), b.clone()],
+                        reason: format!(
+                            "Vertices are only {distance} apart, but the \
+                             minimum distance is {min_distance}"
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    fn check_edge_vertices(&self, report: &mut ValidityReport) {
+        let vertices: Vec<_> = self.topology().vertices().collect();
+
+        for edge in self.topology().edges() {
+            if let Some(bounding) = &edge.get().vertices {
+                for vertex in bounding {
+                    if !vertices.iter().any(|v| v == vertex) {
+                        report.push(ValidityIssue::MissingVertex {
+                            edge: edge.clone(),
+                            vertex: vertex.clone(),
+                            reason: "Edge references a vertex that is not part \
+                                     of the shape"
+                                .into(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_cycle_closure(&self, report: &mut ValidityReport) {
+        for cycle in self.topology().cycles() {
+            if !cycle_is_closed(&cycle) {
+                report.push(ValidityIssue::OpenCycle {
+                    cycle: cycle.clone(),
+                    reason: "Cycle's edges don't form a closed loop".into(),
+                });
+            }
+        }
+    }
+
+    fn check_face_boundaries(&self, report: &mut ValidityReport) {
+        let min_distance = self.geometry().min_distance();
+
+        for face in self.topology().faces() {
+            match &*face.get() {
+                // A boundary-represented face encloses a region only if its
+                // boundary cycles meet *in space*. Unlike the topological
+                // `OpenCycle` check — which walks vertex handles — this compares
+                // the edge endpoints' points, so a cycle whose edges chain up by
+                // handle but whose geometry leaves a physical gap is caught too.
+                Face::Face {
+                    exteriors,
+                    interiors,
+                    ..
+                } => {
+                    for cycle in exteriors.iter().chain(interiors) {
+                        if !boundary_is_coincident(cycle, min_distance) {
+                            report.push(ValidityIssue::NonCoincidentBoundary {
+                                face: face.clone(),
+                                reason: "Face boundary geometry is not \
+                                         coincident: a boundary edge endpoint \
+                                         has no coincident neighbour to meet"
+                                    .into(),
+                            });
+                        }
+                    }
+                }
+                Face::Triangles(triangles) => {
+                    for triangle in triangles {
+                        let [a, b, c] = triangle.points();
+                        let normal = (b - a).cross(&(c - a));
+                        if normal.magnitude().into_f64() == 0. {
+                            report.push(ValidityIssue::DegenerateTriangle {
+                                face: face.clone(),
+                                reason: "Triangle is degenerate (zero area)"
+                                    .into(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Whether a boundary cycle's edges meet end-to-end *in space*
+///
+/// Where [`cycle_is_closed`] checks topological closure by walking vertex
+/// handles, this checks geometric coincidence: every bounded edge endpoint must
+/// have a distinct partner endpoint within `min_distance`. An endpoint left
+/// without a coincident partner is a physical gap in the boundary, even if the
+/// edges were wired up into a topologically closed loop.
+fn boundary_is_coincident(cycle: &Handle<Cycle>, min_distance: f64) -> bool {
+    let mut endpoints = Vec::new();
+    for edge in &cycle.get().edges {
+        if let Some([a, b]) = &edge.get().vertices {
+            endpoints.push(a.get().point());
+            endpoints.push(b.get().point());
+        }
+    }
+
+    // Unbounded edges (a full circle, say) close on themselves and contribute
+    // no endpoint to pair up.
+    if endpoints.is_empty() {
+        return true;
+    }
+
+    // Greedily pair each endpoint with a coincident, not-yet-paired neighbour.
+    // An endpoint with no such partner is a gap in the boundary.
+    let mut paired = vec![false; endpoints.len()];
+    for i in 0..endpoints.len() {
+        if paired[i] {
+            continue;
+        }
+
+        let partner = (0..endpoints.len()).find(|&j| {
+            j != i
+                && !paired[j]
+                && (endpoints[i] - endpoints[j]).magnitude().into_f64()
+                    < min_distance
+        });
+
+        match partner {
+            Some(j) => {
+                paired[i] = true;
+                paired[j] = true;
+            }
+            None => return false,
+        }
+    }
+
+    true
+}
+
+fn cycle_is_closed(cycle: &Handle<Cycle>) -> bool {
+    let edges = &cycle.get().edges;
+
+    // Collect the bounded edges as vertex-pair segments. Edges without bounding
+    // vertices (a full circle, say) already close on themselves and carry no
+    // connectivity constraint, so they're skipped.
+    let segments: Vec<(Handle<Vertex>, Handle<Vertex>)> = edges
+        .iter()
+        .filter_map(|edge| {
+            edge.get()
+                .vertices
+                .as_ref()
+                .map(|[a, b]| (a.clone(), b.clone()))
+        })
+        .collect();
+
+    // No bounded edges means nothing that can be left open.
+    if segments.is_empty() {
+        return true;
+    }
+
+    // A parity count isn't enough: two disjoint loops, or a figure-eight, all
+    // have every vertex appear an even number of times yet aren't a single
+    // closed loop. Instead, walk the edges end-to-end from one segment and
+    // require that the walk returns to its start having used *every* segment —
+    // i.e. the edges form one connected loop with every vertex of degree two.
+    let mut used = vec![false; segments.len()];
+    used[0] = true;
+    let start = segments[0].0.clone();
+    let mut current = segments[0].1.clone();
+    let mut visited = 1;
+
+    while current != start {
+        let next = segments.iter().enumerate().find(|(i, (a, b))| {
+            !used[*i] && (*a == current || *b == current)
+        });
+
+        match next {
+            Some((i, (a, b))) => {
+                used[i] = true;
+                current = if *a == current { b.clone() } else { a.clone() };
+                visited += 1;
+            }
+            // Dead end before returning to the start: the chain is open.
+            None => return false,
+        }
+    }
+
+    // The loop closed; it's a valid cycle only if it used up every edge, so a
+    // second disconnected loop doesn't slip through as "closed".
+    visited == segments.len()
+}
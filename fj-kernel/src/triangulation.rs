@@ -0,0 +1,376 @@
+//! Polygon triangulation via ear clipping
+//!
+//! Sketches are built from point loops, and faces carry a triangle
+//! representation, but there is no general way to turn a non-convex profile —
+//! let alone one with holes — into triangles. This module does exactly that,
+//! in a surface's 2D parameter space.
+//!
+//! [`triangulate`] takes an outer boundary loop plus zero or more hole loops
+//! and emits triangles using ear clipping: it repeatedly finds an "ear" (a
+//! convex vertex whose triangle with its two neighbors contains no other
+//! polygon vertex), clips it, and continues until three vertices remain. Holes
+//! are spliced into the outer loop along a mutually visible bridge before the
+//! clipping starts.
+//!
+//! [`triangulate`] is the triangulation kernel; the path that turns a 2D
+//! profile into face geometry runs through it from [`wkt::Profile::to_face`],
+//! which feeds a parsed outer ring and holes here and wraps the result as
+//! [`Face::Triangles`].
+//!
+//! [`wkt::Profile::to_face`]: crate::wkt::Profile::to_face
+//! [`Face::Triangles`]: crate::topology::Face::Triangles
+
+use fj_math::Point;
+
+/// Triangulate a polygon with holes in 2D parameter space
+///
+/// `outer` is the outer boundary loop; `holes` are the inner loops to cut out.
+/// Winding order is normalized internally (outer counter-clockwise, holes
+/// clockwise), so callers need not get it right. Duplicate and collinear
+/// vertices are tolerated.
+///
+/// Returns the triangles that tessellate the filled region.
+pub fn triangulate(
+    outer: &[Point<2>],
+    holes: &[Vec<Point<2>>],
+) -> Vec<[Point<2>; 3]> {
+    let mut outer = dedup(outer);
+    if outer.len() < 3 {
+        return Vec::new();
+    }
+
+    // Normalize winding: outer counter-clockwise.
+    if signed_area(&outer) < 0. {
+        outer.reverse();
+    }
+
+    // Splice each hole into the outer loop, innermost bridge first. Sorting by
+    // the hole's maximum x keeps the bridges from crossing each other.
+    let mut holes: Vec<Vec<Point<2>>> = holes
+        .iter()
+        .map(|hole| {
+            let mut hole = dedup(hole);
+            // Holes wind clockwise.
+            if signed_area(&hole) > 0. {
+                hole.reverse();
+            }
+            hole
+        })
+        .filter(|hole| hole.len() >= 3)
+        .collect();
+    holes.sort_by(|a, b| max_x(b).partial_cmp(&max_x(a)).unwrap());
+
+    for hole in holes {
+        bridge_hole(&mut outer, &hole);
+    }
+
+    ear_clip(outer)
+}
+
+fn ear_clip(mut polygon: Vec<Point<2>>) -> Vec<[Point<2>; 3]> {
+    let mut triangles = Vec::new();
+
+    // Guard against an infinite loop on malformed input: each full pass that
+    // clips nothing means no ear was found.
+    let mut guard = polygon.len() * polygon.len();
+
+    while polygon.len() > 3 && guard > 0 {
+        guard -= 1;
+
+        let n = polygon.len();
+        let mut clipped = false;
+
+        for i in 0..n {
+            let prev = polygon[(i + n - 1) % n];
+            let curr = polygon[i];
+            let next = polygon[(i + 1) % n];
+
+            if !is_convex(prev, curr, next) {
+                continue;
+            }
+            if area2(prev, curr, next).abs() == 0. {
+                // Collinear ear: clip it without emitting a degenerate
+                // triangle.
+                polygon.remove(i);
+                clipped = true;
+                break;
+            }
+            // A vertex coinciding with a corner of the ear (the doubled
+            // vertices of a hole bridge) doesn't block it.
+            if polygon
+                .iter()
+                .enumerate()
+                .any(|(j, p)| {
+                    j != i
+                        && j != (i + n - 1) % n
+                        && j != (i + 1) % n
+                        && *p != prev
+                        && *p != curr
+                        && *p != next
+                        && point_in_triangle(*p, prev, curr, next)
+                })
+            {
+                continue;
+            }
+
+            triangles.push([prev, curr, next]);
+            polygon.remove(i);
+            clipped = true;
+            break;
+        }
+
+        if !clipped {
+            // No ear found — the remaining polygon is degenerate. Stop rather
+            // than spin.
+            break;
+        }
+    }
+
+    if polygon.len() == 3 && area2(polygon[0], polygon[1], polygon[2]).abs() > 0. {
+        triangles.push([polygon[0], polygon[1], polygon[2]]);
+    }
+
+    triangles
+}
+
+/// Splice `hole` into `outer` along a mutually visible bridge
+///
+/// Uses the standard hole-bridging construction (Eberly): take the hole vertex
+/// `M` with the maximum x, cast a ray from it in +x, and find the closest point
+/// `I` where that ray hits an outer edge. The endpoint `P` of that edge with
+/// the greater x is a bridge candidate. If any *reflex* outer vertex lies inside
+/// the triangle `M, I, P`, it could occlude `P`, so the bridge instead goes to
+/// the reflex vertex whose direction from `M` is closest to the +x ray (nearest
+/// on a tie) — that vertex is guaranteed mutually visible from `M`. The hole is
+/// then inserted into the outer loop with a doubled bridge edge, yielding a
+/// single simple polygon.
+fn bridge_hole(outer: &mut Vec<Point<2>>, hole: &[Point<2>]) {
+    let (hole_start, _) = hole
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| {
+            a.x.into_f64().partial_cmp(&b.x.into_f64()).unwrap()
+        })
+        .unwrap();
+
+    let m = hole[hole_start];
+    let n = outer.len();
+
+    // Cast the +x ray from `M` and find the nearest outer edge it crosses.
+    let mut hit_x = f64::INFINITY;
+    let mut candidate = None;
+    for i in 0..n {
+        let a = outer[i];
+        let b = outer[(i + 1) % n];
+        let (ay, by) = (a.y.into_f64(), b.y.into_f64());
+
+        // Skip horizontal edges; an edge crosses the ray if `M.y` is between
+        // its endpoints' y values.
+        if (ay - by).abs() == 0. {
+            continue;
+        }
+        if (m.y.into_f64() < ay.min(by)) || (m.y.into_f64() > ay.max(by)) {
+            continue;
+        }
+
+        let t = (m.y.into_f64() - ay) / (by - ay);
+        let x = a.x.into_f64() + t * (b.x.into_f64() - a.x.into_f64());
+        if x >= m.x.into_f64() && x < hit_x {
+            hit_x = x;
+            // The endpoint with the greater x is the one `M` faces.
+            let p = if a.x.into_f64() >= b.x.into_f64() {
+                i
+            } else {
+                (i + 1) % n
+            };
+            candidate = Some((p, Point::from([x, m.y.into_f64()])));
+        }
+    }
+
+    let (mut best, intersection) = match candidate {
+        Some(hit) => hit,
+        // No edge to the right (degenerate input): fall back to the nearest
+        // outer vertex so we still produce a single loop.
+        None => {
+            let nearest = (0..n)
+                .min_by(|&i, &j| {
+                    distance2(outer[i], m)
+                        .partial_cmp(&distance2(outer[j], m))
+                        .unwrap()
+                })
+                .unwrap_or(0);
+            return splice(outer, hole, hole_start, nearest);
+        }
+    };
+
+    // `P` might be occluded by a reflex vertex inside the triangle M, I, P.
+    // Among such reflex vertices, the mutually visible bridge is the one whose
+    // direction from `M` is closest to the +x ray, nearest on a tie.
+    let p = outer[best];
+    let mut best_angle = f64::INFINITY;
+    let mut best_dist = f64::INFINITY;
+    for i in 0..n {
+        if i == best {
+            continue;
+        }
+        let r = outer[i];
+        if !is_reflex(outer, i) {
+            continue;
+        }
+        if !point_in_triangle(r, m, intersection, p) {
+            continue;
+        }
+
+        let dx = r.x.into_f64() - m.x.into_f64();
+        let dy = r.y.into_f64() - m.y.into_f64();
+        let angle = dy.atan2(dx).abs();
+        let dist = distance2(r, m);
+        if angle < best_angle || (angle == best_angle && dist < best_dist) {
+            best_angle = angle;
+            best_dist = dist;
+            best = i;
+        }
+    }
+
+    splice(outer, hole, hole_start, best);
+}
+
+/// Splice `hole` (starting at `hole_start`) into `outer` at vertex `best`
+fn splice(
+    outer: &mut Vec<Point<2>>,
+    hole: &[Point<2>],
+    hole_start: usize,
+    best: usize,
+) {
+    let m = hole[hole_start];
+
+    // Build the spliced loop: outer up to and including the bridge vertex, the
+    // hole starting at `hole_start` (wrapping once around), then back across
+    // the doubled bridge edge to the outer vertex.
+    let mut spliced = Vec::with_capacity(outer.len() + hole.len() + 2);
+    spliced.extend_from_slice(&outer[..=best]);
+    for k in 0..hole.len() {
+        spliced.push(hole[(hole_start + k) % hole.len()]);
+    }
+    spliced.push(m);
+    spliced.push(outer[best]);
+    spliced.extend_from_slice(&outer[best + 1..]);
+
+    *outer = spliced;
+}
+
+fn dedup(points: &[Point<2>]) -> Vec<Point<2>> {
+    let mut out: Vec<Point<2>> = Vec::with_capacity(points.len());
+    for &p in points {
+        if out.last().map(|&last| last == p) != Some(true) {
+            out.push(p);
+        }
+    }
+    // Drop a closing vertex that repeats the first.
+    if out.len() > 1 && out.first() == out.last() {
+        out.pop();
+    }
+    out
+}
+
+fn signed_area(polygon: &[Point<2>]) -> f64 {
+    let mut area = 0.;
+    let n = polygon.len();
+    for i in 0..n {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % n];
+        area += a.x.into_f64() * b.y.into_f64() - b.x.into_f64() * a.y.into_f64();
+    }
+    area / 2.
+}
+
+/// Whether outer vertex `i` is reflex (a clockwise turn in a CCW polygon)
+fn is_reflex(polygon: &[Point<2>], i: usize) -> bool {
+    let n = polygon.len();
+    let prev = polygon[(i + n - 1) % n];
+    let curr = polygon[i];
+    let next = polygon[(i + 1) % n];
+    area2(prev, curr, next) < 0.
+}
+
+fn is_convex(a: Point<2>, b: Point<2>, c: Point<2>) -> bool {
+    // Counter-clockwise turn, since the polygon is normalized CCW.
+    area2(a, b, c) >= 0.
+}
+
+fn area2(a: Point<2>, b: Point<2>, c: Point<2>) -> f64 {
+    (b.x.into_f64() - a.x.into_f64()) * (c.y.into_f64() - a.y.into_f64())
+        - (b.y.into_f64() - a.y.into_f64()) * (c.x.into_f64() - a.x.into_f64())
+}
+
+fn point_in_triangle(
+    p: Point<2>,
+    a: Point<2>,
+    b: Point<2>,
+    c: Point<2>,
+) -> bool {
+    let d1 = area2(p, a, b);
+    let d2 = area2(p, b, c);
+    let d3 = area2(p, c, a);
+
+    let has_neg = d1 < 0. || d2 < 0. || d3 < 0.;
+    let has_pos = d1 > 0. || d2 > 0. || d3 > 0.;
+
+    !(has_neg && has_pos)
+}
+
+fn distance2(a: Point<2>, b: Point<2>) -> f64 {
+    let dx = a.x.into_f64() - b.x.into_f64();
+    let dy = a.y.into_f64() - b.y.into_f64();
+    dx * dx + dy * dy
+}
+
+fn max_x(polygon: &[Point<2>]) -> f64 {
+    polygon
+        .iter()
+        .map(|p| p.x.into_f64())
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use super::triangulate;
+
+    #[test]
+    fn triangulate_square() {
+        let square = [
+            Point::from([0., 0.]),
+            Point::from([1., 0.]),
+            Point::from([1., 1.]),
+            Point::from([0., 1.]),
+        ];
+
+        let triangles = triangulate(&square, &[]);
+
+        assert_eq!(triangles.len(), 2);
+    }
+
+    #[test]
+    fn triangulate_square_with_hole() {
+        let outer = vec![
+            Point::from([0., 0.]),
+            Point::from([4., 0.]),
+            Point::from([4., 4.]),
+            Point::from([0., 4.]),
+        ];
+        let hole = vec![
+            Point::from([1., 1.]),
+            Point::from([3., 1.]),
+            Point::from([3., 3.]),
+            Point::from([1., 3.]),
+        ];
+
+        let triangles = triangulate(&outer, &[hole]);
+
+        // Outer quad (4 verts) + hole (4 verts) bridged into one 10-vertex loop
+        // triangulates into 8 triangles.
+        assert_eq!(triangles.len(), 8);
+    }
+}
@@ -0,0 +1,354 @@
+//! WKT text import/export for 2D sketch profiles
+//!
+//! Well-Known Text is the portable format GIS and `geo`-style tooling use for
+//! polygons with holes. This module bridges it into the kernel's 2D profile
+//! geometry so externally generated profiles can flow into the extrude/sweep
+//! pipeline, and so the kernel's 2D data has a round-trippable debug format.
+//!
+//! [`from_wkt`] parses `POLYGON((x y, ...),(hole...))` and `MULTIPOLYGON` into
+//! [`Profile`]s (an outer ring plus holes); [`to_wkt`] and
+//! [`to_wkt_precision`] serialize them back, the latter with a configurable
+//! coordinate precision.
+//!
+//! A [`Profile`] is the kernel-side representation of a sketch profile. It
+//! bridges into the extrude/sweep pipeline through
+//! [`Profile::to_face`], which triangulates the outer ring and holes (via
+//! [`crate::triangulation`]) and wraps the result as a [`Face::Triangles`], so
+//! a profile parsed from WKT can be swept or extruded like any other face.
+
+use std::fmt::{self, Write as _};
+
+use fj_math::{Point, Triangle};
+
+use crate::{topology::Face, triangulation};
+
+/// A 2D profile: an outer ring plus zero or more holes
+///
+/// This mirrors the geometry of a sketch — the outer boundary loop and the
+/// inner loops cut out of it — in a surface's 2D parameter space.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Profile {
+    /// The outer boundary ring
+    pub exterior: Vec<Point<2>>,
+
+    /// The inner rings (holes)
+    pub interiors: Vec<Vec<Point<2>>>,
+}
+
+impl Profile {
+    /// Triangulate the profile in its 2D parameter space
+    ///
+    /// Delegates to [`crate::triangulation`], cutting the holes out of the
+    /// outer ring.
+    pub fn triangulate(&self) -> Vec<[Point<2>; 3]> {
+        triangulation::triangulate(&self.exterior, &self.interiors)
+    }
+
+    /// Turn the profile into a [`Face`] in the x-y plane
+    ///
+    /// Triangulates the profile and embeds each triangle at `z = 0`, producing
+    /// the [`Face::Triangles`] representation the extrude/sweep pipeline
+    /// consumes. This is the bridge from an imported WKT profile into the
+    /// kernel's topology.
+    pub fn to_face(&self) -> Face {
+        let triangles = self
+            .triangulate()
+            .into_iter()
+            .map(|[a, b, c]| {
+                Triangle::from([embed(a), embed(b), embed(c)])
+            })
+            .collect();
+
+        Face::Triangles(triangles)
+    }
+}
+
+/// Embed a 2D point into the x-y plane of 3D space
+fn embed(point: Point<2>) -> Point<3> {
+    Point::from([point.x.into_f64(), point.y.into_f64(), 0.])
+}
+
+/// An error that can occur while parsing WKT
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WktError {
+    /// The input didn't start with a supported geometry keyword
+    UnsupportedGeometry,
+
+    /// The structure of parentheses or commas was malformed
+    Malformed,
+
+    /// A coordinate couldn't be parsed as a number
+    InvalidCoordinate(String),
+}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnsupportedGeometry => {
+                write!(f, "unsupported geometry; expected POLYGON or MULTIPOLYGON")
+            }
+            Self::Malformed => write!(f, "malformed WKT"),
+            Self::InvalidCoordinate(s) => {
+                write!(f, "invalid coordinate: {s}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WktError {}
+
+/// Parse WKT into a list of [`Profile`]s
+///
+/// A `POLYGON` yields a single profile; a `MULTIPOLYGON` yields one per
+/// contained polygon.
+pub fn from_wkt(input: &str) -> Result<Vec<Profile>, WktError> {
+    let input = input.trim();
+
+    if let Some(rest) = strip_keyword(input, "MULTIPOLYGON") {
+        let body = unwrap_parens(rest).ok_or(WktError::Malformed)?;
+        split_groups(body)
+            .into_iter()
+            .map(|group| {
+                // Each group is a whole polygon, still wrapped in its own
+                // parens (`((ring),(hole))`); strip them so `parse_polygon_body`
+                // sees the ring list at the top level, just like the POLYGON arm.
+                let body = unwrap_parens(group).ok_or(WktError::Malformed)?;
+                parse_polygon_body(body)
+            })
+            .collect()
+    } else if let Some(rest) = strip_keyword(input, "POLYGON") {
+        let body = unwrap_parens(rest).ok_or(WktError::Malformed)?;
+        Ok(vec![parse_polygon_body(body)?])
+    } else {
+        Err(WktError::UnsupportedGeometry)
+    }
+}
+
+/// Serialize profiles to WKT with full coordinate precision
+pub fn to_wkt(profiles: &[Profile]) -> String {
+    write_wkt(profiles, None)
+}
+
+/// Serialize profiles to WKT, rounding coordinates to `precision` decimals
+pub fn to_wkt_precision(profiles: &[Profile], precision: usize) -> String {
+    write_wkt(profiles, Some(precision))
+}
+
+fn write_wkt(profiles: &[Profile], precision: Option<usize>) -> String {
+    let mut out = String::new();
+
+    if profiles.len() == 1 {
+        out.push_str("POLYGON");
+        write_polygon(&mut out, &profiles[0], precision);
+    } else {
+        out.push_str("MULTIPOLYGON(");
+        for (i, profile) in profiles.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write_polygon(&mut out, profile, precision);
+        }
+        out.push(')');
+    }
+
+    out
+}
+
+fn write_polygon(
+    out: &mut String,
+    profile: &Profile,
+    precision: Option<usize>,
+) {
+    out.push('(');
+    write_ring(out, &profile.exterior, precision);
+    for interior in &profile.interiors {
+        out.push(',');
+        write_ring(out, interior, precision);
+    }
+    out.push(')');
+}
+
+fn write_ring(out: &mut String, ring: &[Point<2>], precision: Option<usize>) {
+    out.push('(');
+    for (i, point) in ring.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_coord(out, point.x.into_f64(), precision);
+        out.push(' ');
+        write_coord(out, point.y.into_f64(), precision);
+    }
+    out.push(')');
+}
+
+fn write_coord(out: &mut String, value: f64, precision: Option<usize>) {
+    match precision {
+        Some(p) => write!(out, "{value:.*}", p).unwrap(),
+        None => write!(out, "{value}").unwrap(),
+    }
+}
+
+fn parse_polygon_body(body: &str) -> Result<Profile, WktError> {
+    let mut rings = split_groups(body).into_iter();
+
+    let exterior = parse_ring(rings.next().ok_or(WktError::Malformed)?)?;
+    let interiors = rings.map(parse_ring).collect::<Result<_, _>>()?;
+
+    Ok(Profile {
+        exterior,
+        interiors,
+    })
+}
+
+fn parse_ring(ring: &str) -> Result<Vec<Point<2>>, WktError> {
+    let ring = unwrap_parens(ring.trim()).ok_or(WktError::Malformed)?;
+
+    ring.split(',')
+        .map(str::trim)
+        .map(|pair| {
+            let mut coords = pair.split_whitespace();
+            let x = parse_coord(coords.next())?;
+            let y = parse_coord(coords.next())?;
+            if coords.next().is_some() {
+                return Err(WktError::Malformed);
+            }
+            Ok(Point::from([x, y]))
+        })
+        .collect()
+}
+
+fn parse_coord(token: Option<&str>) -> Result<f64, WktError> {
+    let token = token.ok_or(WktError::Malformed)?;
+    token
+        .parse()
+        .map_err(|_| WktError::InvalidCoordinate(token.to_owned()))
+}
+
+fn strip_keyword<'a>(input: &'a str, keyword: &str) -> Option<&'a str> {
+    input.strip_prefix(keyword).map(str::trim_start)
+}
+
+/// Strip one outer layer of matching parentheses
+fn unwrap_parens(input: &str) -> Option<&str> {
+    let input = input.trim();
+    input
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+}
+
+/// Split comma-separated groups at the top level of nesting
+///
+/// Commas inside nested parentheses are left alone, so this separates rings
+/// within a polygon, or polygons within a multipolygon.
+fn split_groups(input: &str) -> Vec<&str> {
+    let mut groups = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                groups.push(input[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    groups.push(input[start..].trim());
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::topology::Face;
+
+    use super::{from_wkt, to_wkt, Profile};
+
+    #[test]
+    fn parse_polygon_with_hole() {
+        let profiles = from_wkt(
+            "POLYGON((0 0, 4 0, 4 4, 0 4),(1 1, 3 1, 3 3, 1 3))",
+        )
+        .unwrap();
+
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].exterior.len(), 4);
+        assert_eq!(profiles[0].interiors.len(), 1);
+        assert_eq!(profiles[0].interiors[0].len(), 4);
+    }
+
+    #[test]
+    fn round_trip() {
+        let profile = Profile {
+            exterior: vec![
+                Point::from([0., 0.]),
+                Point::from([2., 0.]),
+                Point::from([2., 2.]),
+                Point::from([0., 2.]),
+            ],
+            interiors: Vec::new(),
+        };
+
+        let wkt = to_wkt(std::slice::from_ref(&profile));
+        let parsed = from_wkt(&wkt).unwrap();
+
+        assert_eq!(parsed, vec![profile]);
+    }
+
+    #[test]
+    fn parse_multipolygon() {
+        let profiles = from_wkt(
+            "MULTIPOLYGON(((0 0, 1 0, 1 1)),((2 2, 3 2, 3 3),(2.2 2.2, 2.8 2.2, 2.8 2.8)))",
+        )
+        .unwrap();
+
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles[0].interiors.len(), 0);
+        assert_eq!(profiles[1].interiors.len(), 1);
+    }
+
+    #[test]
+    fn round_trip_multiple_profiles() {
+        let profiles = vec![
+            Profile {
+                exterior: vec![
+                    Point::from([0., 0.]),
+                    Point::from([1., 0.]),
+                    Point::from([1., 1.]),
+                ],
+                interiors: Vec::new(),
+            },
+            Profile {
+                exterior: vec![
+                    Point::from([2., 2.]),
+                    Point::from([3., 2.]),
+                    Point::from([3., 3.]),
+                ],
+                interiors: Vec::new(),
+            },
+        ];
+
+        let wkt = to_wkt(&profiles);
+        assert_eq!(from_wkt(&wkt).unwrap(), profiles);
+    }
+
+    #[test]
+    fn profile_to_face() {
+        let profiles = from_wkt(
+            "POLYGON((0 0, 4 0, 4 4, 0 4),(1 1, 3 1, 3 3, 1 3))",
+        )
+        .unwrap();
+
+        let Face::Triangles(triangles) = profiles[0].to_face() else {
+            panic!("expected triangle representation");
+        };
+
+        // A square with a square hole triangulates into eight triangles.
+        assert_eq!(triangles.len(), 8);
+    }
+}